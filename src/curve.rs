@@ -0,0 +1,74 @@
+//! Weierstrass elliptic curves used by ECDSA, and the parameters this
+//! crate needs in order to operate on their points.
+
+pub(crate) mod field;
+pub mod point;
+
+use core::fmt::Debug;
+use generic_array::{ArrayLength, GenericArray};
+
+use self::point::CurvePoint;
+
+/// Identifiers for the elliptic curves this crate supports
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CurveKind {
+    /// NIST P-256 (a.k.a. secp256r1, prime256v1)
+    NistP256,
+
+    /// NIST P-384 (a.k.a. secp384r1)
+    NistP384,
+
+    /// secp256k1 (used by Bitcoin and friends)
+    Secp256k1,
+}
+
+/// Common parameters of a Weierstrass-form elliptic curve, i.e. a curve
+/// of the form `y² = x³ + a·x + b (mod p)`.
+pub trait WeierstrassCurve: Copy + Clone + Debug + Default + Eq + PartialEq + Send + Sync {
+    /// Size of a compressed elliptic curve point: SEC1 tag byte plus `x`
+    type CompressedPointSize: ArrayLength<u8>;
+
+    /// Size of an uncompressed elliptic curve point: SEC1 tag byte plus
+    /// `x` and `y`
+    type UncompressedPointSize: ArrayLength<u8>;
+
+    /// Size of an untagged point, i.e. `UncompressedPointSize` with the
+    /// leading tag byte removed
+    type UntaggedPointSize: ArrayLength<u8>;
+
+    /// Size of a serialized field element or scalar for this curve
+    type ScalarSize: ArrayLength<u8>;
+
+    /// Which curve is this?
+    const CURVE_KIND: CurveKind;
+
+    /// The `a` coefficient of this curve's Weierstrass equation, encoded
+    /// big-endian and left-padded to `ScalarSize` bytes
+    const COEFFICIENT_A: &'static [u8];
+
+    /// The `b` coefficient of this curve's Weierstrass equation, encoded
+    /// big-endian and left-padded to `ScalarSize` bytes
+    const COEFFICIENT_B: &'static [u8];
+
+    /// The prime modulus `p` of this curve's base field, encoded
+    /// big-endian and left-padded to `ScalarSize` bytes
+    const FIELD_MODULUS: &'static [u8];
+
+    /// This curve's identifier as used in the OpenSSH wire format, e.g.
+    /// `"nistp256"` for NIST P-256. See RFC 5656 §10.
+    #[cfg(feature = "ssh")]
+    const SSH_CURVE_ID: &'static str;
+}
+
+/// Curves which can compute `scalar · G`, i.e. fixed-base scalar
+/// multiplication against the curve's conventional base point `G`.
+///
+/// Curves implement this when they (or a backend crate built on them)
+/// ship their own elliptic curve arithmetic. It lets `EcdsaPublicKey` be
+/// derived directly from an `EcdsaSecretKey` without every backend
+/// having to re-implement point derivation itself, while keeping this
+/// crate's core agnostic about which curve provides the arithmetic.
+pub trait FixedBaseScalarMul: WeierstrassCurve {
+    /// Compute `scalar · G`
+    fn mul_base(scalar: &GenericArray<u8, Self::ScalarSize>) -> CurvePoint<Self>;
+}
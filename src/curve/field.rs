@@ -0,0 +1,186 @@
+//! Minimal fixed-width modular arithmetic over a curve's base field.
+//!
+//! This is just enough big-integer math to support SEC1 point
+//! compression/decompression (`§2.3.3`/`§2.3.4`): modular addition and a
+//! Montgomery-ladder-free square-and-multiply modular exponentiation,
+//! both implemented bitwise so they never need a double-width scratch
+//! buffer. It is not a general purpose bignum library, and callers are
+//! responsible for ensuring every input (other than the exponent) is
+//! already reduced mod `m`.
+
+use core::cmp::Ordering;
+
+/// Largest field element this module supports, in bytes. Comfortably
+/// covers every curve this crate currently implements (P-256, P-384,
+/// secp256k1 are all <= 48 bytes).
+pub(crate) const MAX_BYTES: usize = 72;
+
+/// `a >= b`, both big-endian and the same length.
+fn ge(a: &[u8], b: &[u8]) -> bool {
+    a.cmp(b) != Ordering::Less
+}
+
+/// `a - b` in place, assuming `a >= b` and both are the same length.
+fn sub_assign(a: &mut [u8], b: &[u8]) {
+    let mut borrow = 0i16;
+    for i in (0..a.len()).rev() {
+        let diff = i16::from(a[i]) - i16::from(b[i]) - borrow;
+        if diff < 0 {
+            a[i] = (diff + 256) as u8;
+            borrow = 1;
+        } else {
+            a[i] = diff as u8;
+            borrow = 0;
+        }
+    }
+}
+
+/// `(a + b) mod m`, all big-endian and the same length as `m`.
+///
+/// Requires `a < m` and `b < m`, in which case `a + b < 2m` and a single
+/// conditional subtraction suffices to reduce the sum.
+pub fn add_mod(a: &[u8], b: &[u8], m: &[u8]) -> [u8; MAX_BYTES] {
+    let wide = add_mod_wide(a, b, m);
+    let mut out = [0u8; MAX_BYTES];
+    out[..m.len()].copy_from_slice(&wide[1..=m.len()]);
+    out
+}
+
+/// Widened (by one leading byte, to absorb carry) version of [`add_mod`].
+fn add_mod_wide(a: &[u8], b: &[u8], m: &[u8]) -> [u8; MAX_BYTES + 1] {
+    let len = m.len();
+    let mut sum = [0u8; MAX_BYTES + 1];
+    let mut carry = 0u16;
+
+    for i in (0..len).rev() {
+        let s = u16::from(a[i]) + u16::from(b[i]) + carry;
+        sum[i + 1] = s as u8;
+        carry = s >> 8;
+    }
+    sum[0] = carry as u8;
+
+    let mut padded_m = [0u8; MAX_BYTES + 1];
+    padded_m[1..=len].copy_from_slice(m);
+
+    if ge(&sum[..=len], &padded_m[..=len]) {
+        sub_assign(&mut sum[..=len], &padded_m[..=len]);
+    }
+
+    sum
+}
+
+/// `(a * b) mod m`, all big-endian and the same length as `m`.
+///
+/// Computed bit-by-bit ("double and add") over `a` rather than via a
+/// double-width product, so every intermediate stays within `m.len()`
+/// bytes.
+pub fn mul_mod(a: &[u8], b: &[u8], m: &[u8]) -> [u8; MAX_BYTES] {
+    let len = m.len();
+    let mut result = [0u8; MAX_BYTES];
+
+    for byte in &a[..len] {
+        for bit in (0..8).rev() {
+            let doubled = add_mod_wide(&result[..len], &result[..len], m);
+            result[..len].copy_from_slice(&doubled[1..=len]);
+
+            if (byte >> bit) & 1 == 1 {
+                let added = add_mod_wide(&result[..len], b, m);
+                result[..len].copy_from_slice(&added[1..=len]);
+            }
+        }
+    }
+
+    result
+}
+
+/// `a - b`, both big-endian and the same length, assuming `a >= b`.
+pub fn sub(a: &[u8], b: &[u8]) -> [u8; MAX_BYTES] {
+    let mut out = [0u8; MAX_BYTES];
+    out[..a.len()].copy_from_slice(a);
+    sub_assign(&mut out[..a.len()], b);
+    out
+}
+
+/// `(a + 1) >> shift`, big-endian. Used to derive the exponent
+/// `(p + 1) / 4` for square roots mod a prime `p ≡ 3 (mod 4)`.
+pub fn increment_then_shr(a: &[u8], shift: u32) -> [u8; MAX_BYTES] {
+    let mut out = [0u8; MAX_BYTES];
+    out[..a.len()].copy_from_slice(a);
+
+    // a + 1
+    for byte in out[..a.len()].iter_mut().rev() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+
+    // >> shift, shift always < 8 for our one caller
+    let mut carry_bits = 0u8;
+    for byte in out[..a.len()].iter_mut() {
+        let new_carry_bits = *byte << (8 - shift);
+        *byte = (*byte >> shift) | carry_bits;
+        carry_bits = new_carry_bits;
+    }
+
+    out
+}
+
+/// `base^exp mod m` via a Montgomery-ladder-free square-and-multiply,
+/// all big-endian and the same length as `m`.
+pub fn pow_mod(base: &[u8], exp: &[u8], m: &[u8]) -> [u8; MAX_BYTES] {
+    let len = m.len();
+    let mut result = [0u8; MAX_BYTES];
+    result[len - 1] = 1;
+
+    for byte in exp {
+        for bit in (0..8).rev() {
+            let squared = mul_mod(&result[..len], &result[..len], m);
+            result[..len].copy_from_slice(&squared[..len]);
+
+            if (byte >> bit) & 1 == 1 {
+                let multiplied = mul_mod(&result[..len], base, m);
+                result[..len].copy_from_slice(&multiplied[..len]);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // All vectors below are mod m = 251, cross-checked against Python's
+    // arbitrary-precision arithmetic.
+    const M: &[u8] = &[251];
+
+    #[test]
+    fn add_mod_wraps_correctly() {
+        assert_eq!(add_mod(&[200], &[90], M)[0], 39);
+    }
+
+    #[test]
+    fn mul_mod_wraps_correctly() {
+        assert_eq!(mul_mod(&[200], &[90], M)[0], 179);
+    }
+
+    #[test]
+    fn sub_without_wraparound() {
+        assert_eq!(sub(&[200], &[30])[0], 170);
+    }
+
+    #[test]
+    fn increment_then_shr_computes_sqrt_exponent() {
+        // (m + 1) / 4, used to derive SEC1 point decompression's sqrt
+        // exponent for primes p ≡ 3 (mod 4).
+        assert_eq!(increment_then_shr(M, 2)[0], 63);
+    }
+
+    #[test]
+    fn pow_mod_matches_reference() {
+        assert_eq!(pow_mod(&[5], &[117], M)[0], 211);
+    }
+}
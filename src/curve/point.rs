@@ -0,0 +1,32 @@
+//! Elliptic curve points, encoded per the
+//! `Elliptic-Curve-Point-to-Octet-String` algorithm described in
+//! SEC 1: Elliptic Curve Cryptography (Version 2.0) section 2.3.3.
+//!
+//! <http://www.secg.org/sec1-v2.pdf>
+
+use generic_array::GenericArray;
+
+use super::WeierstrassCurve;
+
+/// A raw, untagged elliptic curve point: the affine coordinates `x || y`
+/// with no leading SEC1 tag byte.
+///
+/// This is the natural output of scalar multiplication (see
+/// `FixedBaseScalarMul`): the tag byte only matters once a point is
+/// serialized, so curve backends hand back coordinates in this form and
+/// let `EcdsaPublicKey::from_untagged_point` attach the tag.
+pub struct CurvePoint<C: WeierstrassCurve> {
+    bytes: GenericArray<u8, C::UntaggedPointSize>,
+}
+
+impl<C: WeierstrassCurve> CurvePoint<C> {
+    /// Create a curve point from its raw, untagged `x || y` coordinates
+    pub fn new(bytes: GenericArray<u8, C::UntaggedPointSize>) -> Self {
+        CurvePoint { bytes }
+    }
+
+    /// Consume this point, returning its raw `x || y` coordinates
+    pub fn into_bytes(self) -> GenericArray<u8, C::UntaggedPointSize> {
+        self.bytes
+    }
+}
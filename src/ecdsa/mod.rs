@@ -0,0 +1,7 @@
+//! ECDSA (Elliptic Curve Digital Signature Algorithm) support
+
+mod public_key;
+mod secret_key;
+
+pub use self::public_key::EcdsaPublicKey;
+pub use self::secret_key::EcdsaSecretKey;
@@ -2,28 +2,53 @@
 //! curve points.
 
 use core::fmt::{self, Debug};
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use core::str;
 use generic_array::{typenum::Unsigned, GenericArray};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use curve::point::{CompressedCurvePoint, UncompressedCurvePoint};
-use curve::WeierstrassCurve;
+use curve::field;
+use curve::{FixedBaseScalarMul, WeierstrassCurve};
+use ecdsa::EcdsaSecretKey;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
 use encoding::Encode;
 #[cfg(feature = "encoding")]
 use encoding::{Decode, Encoding};
 use error::Error;
+#[cfg(feature = "ssh")]
+use error::ErrorKind;
 #[allow(unused_imports)]
 use prelude::*;
 use public_key::PublicKey;
+#[cfg(feature = "ssh")]
+use ssh;
 use util::fmt_colon_delimited_hex;
 
-/// ECDSA public keys
-#[derive(Clone, Eq, PartialEq)]
-pub enum EcdsaPublicKey<C: WeierstrassCurve> {
-    /// Compressed Weierstrass elliptic curve point
-    Compressed(CompressedCurvePoint<C>),
+/// ECDSA public keys: a Weierstrass elliptic curve point, compressed or
+/// uncompressed.
+///
+/// Backed by a single `UncompressedPointSize`-sized buffer plus a
+/// logical length, rather than an enum of the two SEC1 point
+/// encodings: a compressed point simply occupies the first
+/// `CompressedPointSize` bytes of the buffer, and the leading tag byte
+/// (`0x02`/`0x03` vs `0x04`) disambiguates the two cases. This spares
+/// every generic caller from having to carry both `CompressedPointSize`
+/// and `UncompressedPointSize` bounds.
+pub struct EcdsaPublicKey<C: WeierstrassCurve> {
+    bytes: GenericArray<u8, C::UncompressedPointSize>,
+    len: usize,
+}
 
-    /// Uncompressed Weierstrass elliptic curve point
-    Uncompressed(UncompressedCurvePoint<C>),
+impl<C: WeierstrassCurve> Clone for EcdsaPublicKey<C> {
+    fn clone(&self) -> Self {
+        EcdsaPublicKey {
+            bytes: self.bytes.clone(),
+            len: self.len,
+        }
+    }
 }
 
 impl<C> EcdsaPublicKey<C>
@@ -39,24 +64,29 @@ where
     /// <http://www.secg.org/sec1-v2.pdf>
     pub fn from_bytes<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
         let slice = bytes.as_ref();
-        let length = slice.len();
-
-        if length == C::CompressedPointSize::to_usize() {
-            let array = GenericArray::clone_from_slice(slice);
-            let point = CompressedCurvePoint::new(array)?;
-            Ok(EcdsaPublicKey::Compressed(point))
-        } else if length == C::UncompressedPointSize::to_usize() {
-            let array = GenericArray::clone_from_slice(slice);
-            let point = UncompressedCurvePoint::new(array)?;
-            Ok(EcdsaPublicKey::Uncompressed(point))
+        let len = slice.len();
+
+        let tag_is_valid = if len == C::CompressedPointSize::to_usize() {
+            slice[0] == 0x02 || slice[0] == 0x03
+        } else if len == C::UncompressedPointSize::to_usize() {
+            slice[0] == 0x04
         } else {
             fail!(
                 KeyInvalid,
                 "invalid length for {:?} public key: {}",
                 C::CURVE_KIND,
-                length
+                len
             );
+        };
+
+        if !tag_is_valid {
+            fail!(KeyInvalid, "invalid point tag byte: 0x{:02x}", slice[0]);
         }
+
+        let mut array: GenericArray<u8, C::UncompressedPointSize> = GenericArray::default();
+        array[..len].copy_from_slice(slice);
+
+        Ok(EcdsaPublicKey { bytes: array, len })
     }
 
     /// Create an ECDSA public key from an compressed elliptic curve point
@@ -69,8 +99,7 @@ where
     where
         B: Into<GenericArray<u8, C::CompressedPointSize>>,
     {
-        let point = CompressedCurvePoint::new(into_bytes)?;
-        Ok(EcdsaPublicKey::Compressed(point))
+        Self::from_bytes(into_bytes.into())
     }
 
     /// Create an ECDSA public key from a raw uncompressed point serialized
@@ -80,20 +109,230 @@ where
     /// `Elliptic-Curve-Point-to-Octet-String` encoding i.e
     /// with the leading `0x04` byte in that encoding removed.
     pub fn from_untagged_point(bytes: &GenericArray<u8, C::UntaggedPointSize>) -> Self {
-        let mut tagged_bytes = GenericArray::default();
-        tagged_bytes.as_mut_slice()[0] = 0x04;
-        tagged_bytes.as_mut_slice()[1..].copy_from_slice(bytes.as_ref());
+        let mut array: GenericArray<u8, C::UncompressedPointSize> = GenericArray::default();
+        array[0] = 0x04;
+        array[1..].copy_from_slice(bytes.as_ref());
+
+        EcdsaPublicKey {
+            bytes: array,
+            len: C::UncompressedPointSize::to_usize(),
+        }
+    }
 
-        EcdsaPublicKey::Uncompressed(UncompressedCurvePoint::new(tagged_bytes).unwrap())
+    /// Is this public key in its compressed SEC1 representation?
+    #[inline]
+    pub fn is_compressed(&self) -> bool {
+        self.len == C::CompressedPointSize::to_usize()
     }
 
     /// Obtain public key as a byte array reference
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
-        match self {
-            EcdsaPublicKey::Compressed(ref point) => point.as_bytes(),
-            EcdsaPublicKey::Uncompressed(ref point) => point.as_bytes(),
+        &self.bytes[..self.len]
+    }
+
+    /// Convert this public key to its compressed SEC1 representation
+    /// (`Elliptic-Curve-Point-to-Octet-String`, compressed form), i.e.
+    /// the tag byte `0x02`/`0x03` followed by the `x`-coordinate alone.
+    ///
+    /// This is a cheap no-op if the key is already compressed.
+    pub fn compress(&self) -> Self {
+        if self.is_compressed() {
+            return self.clone();
+        }
+
+        let field_size = C::ScalarSize::to_usize();
+        let bytes = self.as_bytes();
+        let x = &bytes[1..1 + field_size];
+        let y_is_even = bytes[bytes.len() - 1] & 1 == 0;
+
+        let mut array: GenericArray<u8, C::UncompressedPointSize> = GenericArray::default();
+        array[0] = if y_is_even { 0x02 } else { 0x03 };
+        array[1..1 + field_size].copy_from_slice(x);
+
+        EcdsaPublicKey {
+            bytes: array,
+            len: C::CompressedPointSize::to_usize(),
+        }
+    }
+
+    /// Recover this public key's uncompressed SEC1 representation
+    /// (`Octet-String-to-Elliptic-Curve-Point`, SEC 1 §2.3.4) from its
+    /// compressed `tag || x` encoding, by solving the curve equation
+    /// `y² = x³ + a·x + b (mod p)` for `y` and picking the root whose
+    /// parity matches the compression tag.
+    ///
+    /// This is a cheap no-op if the key is already uncompressed.
+    ///
+    /// Returns `Error::KeyInvalid` if `x` is not the coordinate of any
+    /// point on the curve.
+    ///
+    /// Only supports fields whose modulus `p` satisfies `p ≡ 3 (mod 4)`,
+    /// which holds for every curve this crate currently implements
+    /// (P-256, P-384, secp256k1).
+    pub fn decompress(&self) -> Result<Self, Error> {
+        if !self.is_compressed() {
+            return Ok(self.clone());
+        }
+
+        let field_size = C::ScalarSize::to_usize();
+        let bytes = self.as_bytes();
+        let tag = bytes[0];
+        let x = &bytes[1..];
+
+        let p = C::FIELD_MODULUS;
+
+        // `x` must be a reduced field element, i.e. `x < p`.
+        if x >= p {
+            fail!(KeyInvalid, "{:?} public key x-coordinate is out of range", C::CURVE_KIND);
+        }
+
+        let x_squared = field::mul_mod(x, x, p);
+        let x_cubed = field::mul_mod(&x_squared[..field_size], x, p);
+        let a_times_x = field::mul_mod(C::COEFFICIENT_A, x, p);
+        let x_cubed_plus_ax = field::add_mod(&x_cubed[..field_size], &a_times_x[..field_size], p);
+        let rhs = field::add_mod(&x_cubed_plus_ax[..field_size], C::COEFFICIENT_B, p);
+
+        // p ≡ 3 (mod 4), so sqrt(rhs) = rhs^((p + 1) / 4) mod p.
+        let sqrt_exponent = field::increment_then_shr(p, 2);
+        let y = field::pow_mod(&rhs[..field_size], &sqrt_exponent[..field_size], p);
+
+        let y_squared = field::mul_mod(&y[..field_size], &y[..field_size], p);
+        if y_squared[..field_size] != rhs[..field_size] {
+            fail!(
+                KeyInvalid,
+                "{:?} public key is not a point on the curve",
+                C::CURVE_KIND
+            );
+        }
+
+        let y = if y[field_size - 1] & 1 == tag & 1 {
+            y
+        } else {
+            field::sub(p, &y[..field_size])
+        };
+
+        let mut array: GenericArray<u8, C::UncompressedPointSize> = GenericArray::default();
+        array[0] = 0x04;
+        array[1..1 + field_size].copy_from_slice(x);
+        array[1 + field_size..].copy_from_slice(&y[..field_size]);
+
+        Ok(EcdsaPublicKey {
+            bytes: array,
+            len: C::UncompressedPointSize::to_usize(),
+        })
+    }
+}
+
+impl<C> Eq for EcdsaPublicKey<C> where C: WeierstrassCurve {}
+
+impl<C> PartialEq for EcdsaPublicKey<C>
+where
+    C: WeierstrassCurve,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<C> EcdsaPublicKey<C>
+where
+    C: WeierstrassCurve + FixedBaseScalarMul,
+{
+    /// Derive the public key corresponding to `secret`, by computing
+    /// `secret · G` via the curve's `FixedBaseScalarMul` implementation.
+    ///
+    /// Only available for curves which implement `FixedBaseScalarMul`
+    /// (i.e. ship their own elliptic curve arithmetic); curves without
+    /// it must be supplied public key bytes directly (`from_bytes`,
+    /// `from_compressed_point`, ...) instead.
+    pub fn from_secret_key(secret: &EcdsaSecretKey<C>, compress: bool) -> Result<Self, Error> {
+        let point = C::mul_base(secret.as_scalar());
+        let uncompressed = EcdsaPublicKey::from_untagged_point(&point.into_bytes());
+        Ok(if compress {
+            uncompressed.compress()
+        } else {
+            uncompressed
+        })
+    }
+}
+
+#[cfg(feature = "ssh")]
+impl<C> EcdsaPublicKey<C>
+where
+    C: WeierstrassCurve,
+{
+    /// This key's OpenSSH algorithm name, e.g. `"ecdsa-sha2-nistp256"`
+    fn ssh_algorithm_name(&self) -> String {
+        format!("ecdsa-sha2-{}", C::SSH_CURVE_ID)
+    }
+
+    /// Encode this public key as a raw SSH wire-format blob: the
+    /// length-prefixed fields `string "ecdsa-sha2-<curve-id>"`,
+    /// `string "<curve-id>"`, and `string Q`, where `Q` is this key's
+    /// SEC1 `Elliptic-Curve-Point-to-Octet-String` encoding.
+    ///
+    /// RFC 5656 §3.1 requires `Q` to be in its uncompressed form, so a
+    /// compressed key is decompressed first.
+    pub fn encode_ssh(&self) -> Result<Vec<u8>, Error> {
+        let uncompressed = self.decompress()?;
+        let mut blob = Vec::new();
+        ssh::write_string_field(&mut blob, self.ssh_algorithm_name().as_bytes());
+        ssh::write_string_field(&mut blob, C::SSH_CURVE_ID.as_bytes());
+        ssh::write_string_field(&mut blob, uncompressed.as_bytes());
+        Ok(blob)
+    }
+
+    /// Decode a public key from a raw SSH wire-format blob
+    pub fn decode_ssh(blob: &[u8]) -> Result<Self, Error> {
+        let (algorithm, rest) = ssh::read_string_field(blob)?;
+        let (curve_id, rest) = ssh::read_string_field(rest)?;
+        let (point, _) = ssh::read_string_field(rest)?;
+
+        let key = Self::from_bytes(point)?;
+
+        if algorithm != key.ssh_algorithm_name().as_bytes() || curve_id != C::SSH_CURVE_ID.as_bytes()
+        {
+            fail!(
+                KeyInvalid,
+                "SSH key algorithm/curve mismatch for {:?}",
+                C::CURVE_KIND
+            );
+        }
+
+        Ok(key)
+    }
+
+    /// Format this public key in OpenSSH's one-line `authorized_keys`
+    /// format: `"ecdsa-sha2-<curve-id> <base64 blob>"`.
+    pub fn to_openssh(&self) -> Result<String, Error> {
+        Ok(format!(
+            "{} {}",
+            self.ssh_algorithm_name(),
+            ssh::base64_encode(&self.encode_ssh()?)
+        ))
+    }
+
+    /// Parse a public key from OpenSSH's one-line `authorized_keys`
+    /// format: `"ecdsa-sha2-<curve-id> <base64 blob>"`.
+    pub fn from_openssh(s: &str) -> Result<Self, Error> {
+        let mut fields = s.split_whitespace();
+
+        let algorithm = fields
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::Parse, "missing SSH key algorithm"))?;
+
+        let base64_blob = fields
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::Parse, "missing SSH key data"))?;
+
+        let key = Self::decode_ssh(&ssh::base64_decode(base64_blob)?)?;
+
+        if algorithm != key.ssh_algorithm_name() {
+            fail!(KeyInvalid, "unexpected SSH key algorithm: {}", algorithm);
         }
+
+        Ok(key)
     }
 }
 
@@ -156,3 +395,234 @@ where
 }
 
 impl<C: WeierstrassCurve> PublicKey for EcdsaPublicKey<C> {}
+
+/// Large enough to hold the lowercase hex encoding of any uncompressed
+/// SEC1 point (tag byte plus two field elements) this crate supports,
+/// so (de)serializing as hex never needs a heap allocation.
+#[cfg(feature = "serde")]
+const MAX_HEX_LEN: usize = (1 + 2 * field::MAX_BYTES) * 2;
+
+#[cfg(feature = "serde")]
+impl<C> Serialize for EcdsaPublicKey<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Serialize this public key as a lowercase hex string for
+    /// human-readable formats (JSON, TOML, ...), or as raw SEC1-encoded
+    /// bytes for binary formats (bincode, CBOR, ...).
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            let bytes = self.as_bytes();
+            let mut hex = [0u8; MAX_HEX_LEN];
+
+            for (i, byte) in bytes.iter().enumerate() {
+                hex[i * 2] = hex_nibble(byte >> 4);
+                hex[i * 2 + 1] = hex_nibble(byte & 0x0f);
+            }
+
+            let hex = str::from_utf8(&hex[..bytes.len() * 2])
+                .expect("hex digits are always valid UTF-8");
+
+            serializer.serialize_str(hex)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> Deserialize<'de> for EcdsaPublicKey<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Deserialize a public key from either a lowercase hex string
+    /// (human-readable formats) or raw SEC1-encoded bytes (binary
+    /// formats), routing the decoded bytes through `from_bytes` so a
+    /// compressed or uncompressed encoding round-trips into a key in
+    /// the same representation.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PublicKeyVisitor<C: WeierstrassCurve>(PhantomData<C>);
+
+        impl<'de, C: WeierstrassCurve> de::Visitor<'de> for PublicKeyVisitor<C> {
+            type Value = EcdsaPublicKey<C>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a SEC1-encoded elliptic curve point, as hex or raw bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                if !value.len().is_multiple_of(2) {
+                    return Err(E::custom("hex-encoded public key has odd length"));
+                }
+
+                let decoded_len = value.len() / 2;
+                let mut array: GenericArray<u8, C::UncompressedPointSize> = GenericArray::default();
+
+                if decoded_len > array.len() {
+                    return Err(E::custom("hex-encoded public key is too long"));
+                }
+
+                let value = value.as_bytes();
+
+                for i in 0..decoded_len {
+                    let hi = hex_value(value[i * 2]).map_err(E::custom)?;
+                    let lo = hex_value(value[i * 2 + 1]).map_err(E::custom)?;
+                    array[i] = (hi << 4) | lo;
+                }
+
+                EcdsaPublicKey::from_bytes(&array[..decoded_len]).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+                EcdsaPublicKey::from_bytes(value).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PublicKeyVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_bytes(PublicKeyVisitor(PhantomData))
+        }
+    }
+}
+
+/// Encode a nibble as a lowercase ASCII hex digit
+#[cfg(feature = "serde")]
+fn hex_nibble(nibble: u8) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        _ => b'a' + (nibble - 10),
+    }
+}
+
+/// Parse a single ASCII hex digit (either case) into its nibble value
+#[cfg(feature = "serde")]
+fn hex_value(digit: u8) -> Result<u8, Error> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => fail!(Parse, "invalid hex digit: 0x{:02x}", digit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use curve::point::CurvePoint;
+    use curve::CurveKind;
+    use error::ErrorKind;
+    use generic_array::typenum::{U2, U3, U4, U5};
+
+    /// A toy curve over `y² = x³ + x + 2 (mod 251)`, used only to
+    /// exercise this module's logic against real (if tiny) field
+    /// arithmetic: `(x, y) = (1, 249)` is a point on the curve, and
+    /// `(1, 2)` is its even-`y` complement.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+    struct TestCurve;
+
+    impl WeierstrassCurve for TestCurve {
+        type CompressedPointSize = U3;
+        type UncompressedPointSize = U5;
+        type UntaggedPointSize = U4;
+        type ScalarSize = U2;
+
+        const CURVE_KIND: CurveKind = CurveKind::NistP256;
+        const COEFFICIENT_A: &'static [u8] = &[0x00, 0x01];
+        const COEFFICIENT_B: &'static [u8] = &[0x00, 0x02];
+        const FIELD_MODULUS: &'static [u8] = &[0x00, 0xfb];
+        #[cfg(feature = "ssh")]
+        const SSH_CURVE_ID: &'static str = "nistp256";
+    }
+
+    impl FixedBaseScalarMul for TestCurve {
+        fn mul_base(scalar: &GenericArray<u8, U2>) -> CurvePoint<Self> {
+            // Pretend every scalar maps to the fixed point (1, 249);
+            // enough to prove `from_secret_key` wires the pieces
+            // together without needing real curve arithmetic here.
+            assert_ne!(scalar.as_slice(), [0, 0], "scalar must be nonzero");
+            CurvePoint::new(GenericArray::clone_from_slice(&[0x00, 0x01, 0x00, 0xf9]))
+        }
+    }
+
+    fn test_point() -> EcdsaPublicKey<TestCurve> {
+        let untagged = GenericArray::clone_from_slice(&[0x00, 0x01, 0x00, 0xf9]);
+        EcdsaPublicKey::from_untagged_point(&untagged)
+    }
+
+    #[test]
+    fn compress_decompress_round_trip() {
+        let uncompressed = test_point();
+        assert!(!uncompressed.is_compressed());
+
+        let compressed = uncompressed.compress();
+        assert!(compressed.is_compressed());
+        assert_eq!(compressed.as_bytes(), &[0x03, 0x00, 0x01]);
+
+        let decompressed = compressed.decompress().expect("point is on the curve");
+        assert_eq!(decompressed, uncompressed);
+    }
+
+    #[test]
+    fn decompress_rejects_point_not_on_curve() {
+        // x = 0 has no corresponding y on this curve (rhs = 2 is not a
+        // quadratic residue mod 251).
+        let not_on_curve = EcdsaPublicKey::<TestCurve>::from_bytes(&[0x02, 0x00, 0x00][..])
+            .expect("well-formed tag/length");
+
+        let err = not_on_curve.decompress().expect_err("x=0 is not on the curve");
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn from_secret_key_derives_and_compresses() {
+        let secret = EcdsaSecretKey::new(GenericArray::clone_from_slice(&[0x00, 0x01]));
+
+        let uncompressed =
+            EcdsaPublicKey::from_secret_key(&secret, false).expect("derivable public key");
+        assert_eq!(uncompressed, test_point());
+
+        let compressed =
+            EcdsaPublicKey::from_secret_key(&secret, true).expect("derivable public key");
+        assert!(compressed.is_compressed());
+        assert_eq!(compressed.decompress().unwrap(), test_point());
+    }
+
+    #[cfg(feature = "ssh")]
+    #[test]
+    fn ssh_round_trip_always_uncompressed() {
+        let compressed = test_point().compress();
+
+        let openssh = compressed.to_openssh().expect("point is on the curve");
+        assert!(openssh.starts_with("ecdsa-sha2-nistp256 "));
+
+        let parsed = EcdsaPublicKey::<TestCurve>::from_openssh(&openssh).expect("round trip");
+        assert!(!parsed.is_compressed(), "SSH wire format is always uncompressed");
+        assert_eq!(parsed, test_point());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_human_readable_round_trip_is_hex() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        assert_tokens(&test_point().readable(), &[Token::Str("04000100f9")]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_binary_round_trip_is_raw_bytes() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        assert_tokens(
+            &test_point().compact(),
+            &[Token::Bytes(&[0x04, 0x00, 0x01, 0x00, 0xf9])],
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! ECDSA secret (private) keys: a serialized scalar.
+//!
+//! To be usable, the scalar should be in `[1, n)`, where `n` is the
+//! order of the curve's base point, but `WeierstrassCurve` doesn't
+//! expose `n` and `new` performs no validation: callers are
+//! responsible for supplying a scalar already in range, the same way
+//! `curve::field`'s functions trust their inputs are already reduced.
+
+use generic_array::GenericArray;
+
+use curve::WeierstrassCurve;
+
+/// ECDSA secret keys
+pub struct EcdsaSecretKey<C: WeierstrassCurve>(GenericArray<u8, C::ScalarSize>);
+
+impl<C: WeierstrassCurve> Clone for EcdsaSecretKey<C> {
+    fn clone(&self) -> Self {
+        EcdsaSecretKey(self.0.clone())
+    }
+}
+
+impl<C> EcdsaSecretKey<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Create an ECDSA secret key from a big-endian serialized scalar
+    pub fn new(scalar: GenericArray<u8, C::ScalarSize>) -> Self {
+        EcdsaSecretKey(scalar)
+    }
+
+    /// Borrow this secret key's scalar bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Borrow this secret key's scalar
+    pub fn as_scalar(&self) -> &GenericArray<u8, C::ScalarSize> {
+        &self.0
+    }
+}
@@ -0,0 +1,100 @@
+//! Binary-to-text encodings for keys and signatures
+
+#[cfg(feature = "alloc")]
+use prelude::*;
+
+use error::Error;
+
+/// Supported binary-to-text encodings
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    /// Raw binary, i.e. a no-op encoding
+    Raw,
+
+    /// Lowercase hexadecimal
+    Hex,
+}
+
+impl Encoding {
+    /// Decode `encoded` into `out`, returning the number of bytes written
+    pub fn decode(self, encoded: &[u8], out: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            Encoding::Raw => {
+                if encoded.len() > out.len() {
+                    fail!(Parse, "encoded data too long: {} bytes", encoded.len());
+                }
+
+                out[..encoded.len()].copy_from_slice(encoded);
+                Ok(encoded.len())
+            }
+            Encoding::Hex => {
+                if !encoded.len().is_multiple_of(2) {
+                    fail!(Parse, "hex string has odd length: {}", encoded.len());
+                }
+
+                let decoded_len = encoded.len() / 2;
+
+                if decoded_len > out.len() {
+                    fail!(Parse, "decoded data too long: {} bytes", decoded_len);
+                }
+
+                for i in 0..decoded_len {
+                    let hi = hex_value(encoded[i * 2])?;
+                    let lo = hex_value(encoded[i * 2 + 1])?;
+                    out[i] = (hi << 4) | lo;
+                }
+
+                Ok(decoded_len)
+            }
+        }
+    }
+
+    /// Encode `data`, allocating a freshly-owned buffer for the result
+    #[cfg(feature = "alloc")]
+    pub fn encode_vec(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Encoding::Raw => data.to_vec(),
+            Encoding::Hex => {
+                let mut out = Vec::with_capacity(data.len() * 2);
+
+                for byte in data {
+                    out.push(hex_nibble(byte >> 4));
+                    out.push(hex_nibble(byte & 0x0f));
+                }
+
+                out
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn hex_nibble(nibble: u8) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        _ => b'a' + (nibble - 10),
+    }
+}
+
+/// Parse a single ASCII hex digit (either case) into its nibble value
+fn hex_value(digit: u8) -> Result<u8, Error> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => fail!(Parse, "invalid hex digit: 0x{:02x}", digit),
+    }
+}
+
+/// Decode a type from bytes in a given `Encoding`
+pub trait Decode: Sized {
+    /// Decode `encoded_bytes` in the given `Encoding` into `Self`
+    fn decode(encoded_bytes: &[u8], encoding: Encoding) -> Result<Self, Error>;
+}
+
+/// Encode a type to bytes in a given `Encoding`
+#[cfg(feature = "alloc")]
+pub trait Encode {
+    /// Encode `self` in the given `Encoding`
+    fn encode(&self, encoding: Encoding) -> Vec<u8>;
+}
@@ -0,0 +1,93 @@
+//! Error types
+
+use core::fmt::{self, Display};
+
+#[cfg(feature = "alloc")]
+use prelude::*;
+
+/// Kinds of errors
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// Input/output errors
+    Io,
+
+    /// Cryptographic key is invalid or malformed
+    KeyInvalid,
+
+    /// Parse errors
+    Parse,
+
+    /// Signature is invalid
+    SignatureInvalid,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ErrorKind::Io => "I/O error",
+            ErrorKind::KeyInvalid => "invalid key",
+            ErrorKind::Parse => "parse error",
+            ErrorKind::SignatureInvalid => "invalid signature",
+        };
+
+        write!(f, "{}", description)
+    }
+}
+
+/// Error type
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    description: Option<String>,
+}
+
+impl Error {
+    /// Create a new error of the given kind, with a description of what
+    /// went wrong
+    #[cfg(feature = "alloc")]
+    pub fn new(kind: ErrorKind, description: impl Display) -> Self {
+        Error {
+            kind,
+            description: Some(description.to_string()),
+        }
+    }
+
+    /// Create a new error of the given kind, with a description of what
+    /// went wrong
+    #[cfg(not(feature = "alloc"))]
+    pub fn new(kind: ErrorKind, _description: impl Display) -> Self {
+        Error { kind }
+    }
+
+    /// Obtain the `ErrorKind` for this error
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        {
+            if let Some(ref description) = self.description {
+                return write!(f, "{}: {}", self.kind, description);
+            }
+        }
+
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Create and return an error with a formatted message
+macro_rules! fail {
+    ($kind:ident, $msg:expr) => {
+        return Err($crate::error::Error::new($crate::error::ErrorKind::$kind, $msg))
+    };
+    ($kind:ident, $fmt:expr, $($arg:tt)+) => {
+        fail!($kind, format_args!($fmt, $($arg)+))
+    };
+}
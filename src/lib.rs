@@ -0,0 +1,35 @@
+//! Signatory: a multi-provider digital signature library
+//!
+//! This crate provides a thin, no_std-friendly abstraction over the
+//! digital signature primitives (ECDSA, Ed25519, ...) supported by the
+//! various `signatory-*` provider crates.
+
+#![no_std]
+#![deny(warnings, missing_docs, trivial_casts, trivial_numeric_casts)]
+#![deny(unsafe_code)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+extern crate generic_array;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[macro_use]
+mod error;
+
+pub mod curve;
+pub mod ecdsa;
+#[cfg(feature = "encoding")]
+pub mod encoding;
+pub mod prelude;
+pub mod public_key;
+#[cfg(feature = "ssh")]
+pub mod ssh;
+mod util;
+
+pub use error::{Error, ErrorKind};
+pub use public_key::PublicKey;
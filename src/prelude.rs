@@ -0,0 +1,10 @@
+//! Re-exports of `alloc` types used throughout this crate when the
+//! `alloc` feature is enabled, so downstream modules can `use prelude::*;`
+//! instead of juggling `std`-vs-`alloc` imports themselves.
+
+#[cfg(feature = "alloc")]
+pub use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
@@ -0,0 +1,5 @@
+//! Trait for public key types
+
+/// Marker trait for public key types exposed by this crate's signature
+/// algorithm modules (e.g. `ecdsa::EcdsaPublicKey`).
+pub trait PublicKey: Clone + Eq + PartialEq + Sized {}
@@ -0,0 +1,162 @@
+//! OpenSSH wire-format helpers shared by this crate's key types.
+//!
+//! Only the pieces needed to read and write `authorized_keys`/SSH
+//! protocol public keys are implemented here: base64 (standard
+//! alphabet, `=` padding, no line wrapping) and the length-prefixed
+//! `string` fields used by the SSH public key blob format (RFC 4253
+//! §5.6, RFC 5656 §3.1 for ECDSA).
+
+#[cfg(feature = "alloc")]
+use prelude::*;
+
+use error::Error;
+
+#[cfg(feature = "alloc")]
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Base64-encode `data` using the standard alphabet with `=` padding
+#[cfg(feature = "alloc")]
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+/// Decode a standard-alphabet base64 string into a byte vector
+#[cfg(feature = "alloc")]
+pub fn base64_decode(encoded: &str) -> Result<Vec<u8>, Error> {
+    let encoded = encoded.trim_end_matches('=');
+    let mut out = Vec::with_capacity(encoded.len() * 3 / 4);
+    let mut bits = 0u32;
+    let mut bit_count = 0u32;
+
+    for c in encoded.bytes() {
+        bits = (bits << 6) | u32::from(base64_value(c)?);
+        bit_count += 6;
+
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push((bits >> bit_count) as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(feature = "alloc")]
+fn base64_value(c: u8) -> Result<u8, Error> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => fail!(Parse, "invalid base64 character: 0x{:02x}", c),
+    }
+}
+
+/// Append a length-prefixed (`string`) field to an SSH wire-format blob
+#[cfg(feature = "alloc")]
+pub fn write_string_field(out: &mut Vec<u8>, field: &[u8]) {
+    out.extend_from_slice(&(field.len() as u32).to_be_bytes());
+    out.extend_from_slice(field);
+}
+
+/// Read a length-prefixed (`string`) field from an SSH wire-format
+/// blob, returning the field and the unconsumed remainder of `input`
+pub fn read_string_field(input: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    if input.len() < 4 {
+        fail!(Parse, "truncated SSH wire format: missing length prefix");
+    }
+
+    let (len_bytes, rest) = input.split_at(4);
+    let len =
+        u32::from_be_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
+
+    if rest.len() < len {
+        fail!(
+            Parse,
+            "truncated SSH wire format: field shorter than its length prefix"
+        );
+    }
+
+    Ok(rest.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use error::ErrorKind;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn base64_round_trip_with_various_padding() {
+        for data in &[&b""[..], &b"f"[..], &b"fo"[..], &b"foo"[..], &b"foob"[..]] {
+            let encoded = base64_encode(data);
+            assert_eq!(base64_decode(&encoded).unwrap(), *data);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn base64_encode_matches_known_vector() {
+        // RFC 4648 §10 test vector.
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn base64_decode_rejects_invalid_character() {
+        let err = base64_decode("not valid base64!!").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn string_field_round_trip() {
+        let mut blob = Vec::new();
+        write_string_field(&mut blob, b"ssh-ed25519");
+        write_string_field(&mut blob, b"rest");
+
+        let (first, rest) = read_string_field(&blob).unwrap();
+        assert_eq!(first, b"ssh-ed25519");
+
+        let (second, rest) = read_string_field(rest).unwrap();
+        assert_eq!(second, b"rest");
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn read_string_field_rejects_missing_length_prefix() {
+        let err = read_string_field(&[0, 0, 1]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn read_string_field_rejects_truncated_field() {
+        let err = read_string_field(&[0, 0, 0, 5, b'h', b'i']).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+    }
+}
@@ -0,0 +1,14 @@
+//! Miscellaneous helpers shared across this crate
+
+use core::fmt;
+
+/// Write `bytes` to `f` as colon-delimited lowercase hex, e.g. `ab:cd:ef`
+pub fn fmt_colon_delimited_hex(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            write!(f, ":")?;
+        }
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}